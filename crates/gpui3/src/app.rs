@@ -1,17 +1,19 @@
 use crate::{
-    current_platform, AnyWindowHandle, Context, LayoutId, MainThreadOnly, Platform, Reference,
-    RootView, TextSystem, Window, WindowContext, WindowHandle, WindowId,
+    current_platform, AnyWindowHandle, Context, LayoutId, MainThreadOnly, Platform,
+    PlatformDispatcher, Reference, RootView, TextSystem, Window, WindowContext, WindowHandle,
+    WindowId,
 };
 use anyhow::{anyhow, Result};
-use collections::{HashMap, VecDeque};
+use collections::{HashMap, HashSet, VecDeque};
 use futures::{future, Future};
-use parking_lot::Mutex;
+use parking_lot::{Mutex, MutexGuard};
 use slotmap::SlotMap;
 use smallvec::SmallVec;
 use std::{
-    any::Any,
+    any::{Any, TypeId},
+    fmt,
     marker::PhantomData,
-    sync::{Arc, Weak},
+    sync::{mpsc, Arc, Weak},
 };
 
 #[derive(Clone)]
@@ -32,9 +34,12 @@ impl App {
         let text_system = Arc::new(TextSystem::new(platform.text_system()));
         let mut entities = SlotMap::with_key();
         let unit_entity_id = entities.insert(Some(Box::new(()) as Box<dyn Any + Send>));
+        let (main_thread_task_tx, main_thread_task_rx) = mpsc::channel();
+        let main_thread_entities = MainThreadOnly::new(SlotMap::with_key(), dispatcher.clone());
         Self(Arc::new_cyclic(|this| {
             Mutex::new(AppContext {
                 this: this.clone(),
+                dispatcher: dispatcher.clone(),
                 platform: MainThreadOnly::new(platform, dispatcher),
                 text_system,
                 unit_entity_id,
@@ -43,6 +48,14 @@ impl App {
                 pending_updates: 0,
                 pending_effects: Default::default(),
                 observers: Default::default(),
+                event_subscribers: Default::default(),
+                globals: Default::default(),
+                global_observers: Default::default(),
+                plugin_names: Default::default(),
+                sub_apps: Default::default(),
+                main_thread_entities,
+                main_thread_task_tx: ThreadLocalTaskSender(main_thread_task_tx),
+                main_thread_task_rx,
                 layout_id_buffer: Default::default(),
             })
         }))
@@ -59,12 +72,104 @@ impl App {
             on_finish_launching(cx);
         }));
     }
+
+    /// Install one or more [`Plugin`]s, giving them a chance to register entities,
+    /// observers, and windows on the app. Returns an error if a plugin with the
+    /// same name has already been added.
+    pub fn add_plugins(&self, plugins: impl Plugins) -> Result<()> {
+        plugins.add_to_app(&mut *self.0.lock())
+    }
+}
+
+/// A reusable unit of app setup, such as registering entities, observers, or
+/// bootstrapping a window, that can be composed with other plugins instead of
+/// being written inline in `on_finish_launching`.
+pub trait Plugin: 'static {
+    fn build(&self, cx: &mut AppContext);
+
+    /// A unique name identifying this plugin, used to detect duplicate
+    /// installation. Defaults to the plugin's type name.
+    fn name(&self) -> &str {
+        std::any::type_name::<Self>()
+    }
+}
+
+/// One or more [`Plugin`]s that can be added to an [`App`] in a single call,
+/// e.g. a tuple `(PluginA, PluginB)` or a `Vec<P>`.
+pub trait Plugins {
+    fn add_to_app(self, cx: &mut AppContext) -> Result<()>;
 }
 
+impl<P: Plugin> Plugins for P {
+    fn add_to_app(self, cx: &mut AppContext) -> Result<()> {
+        cx.add_plugin(self)
+    }
+}
+
+impl<P: Plugin> Plugins for Vec<P> {
+    fn add_to_app(self, cx: &mut AppContext) -> Result<()> {
+        for plugin in self {
+            plugin.add_to_app(cx)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_plugins_for_tuple {
+    ($($plugin:ident),+) => {
+        impl<$($plugin: Plugin),+> Plugins for ($($plugin,)+) {
+            #[allow(non_snake_case)]
+            fn add_to_app(self, cx: &mut AppContext) -> Result<()> {
+                let ($($plugin,)+) = self;
+                $($plugin.add_to_app(cx)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_plugins_for_tuple!(A);
+impl_plugins_for_tuple!(A, B);
+impl_plugins_for_tuple!(A, B, C);
+impl_plugins_for_tuple!(A, B, C, D);
+impl_plugins_for_tuple!(A, B, C, D, E);
+impl_plugins_for_tuple!(A, B, C, D, E, F);
+impl_plugins_for_tuple!(A, B, C, D, E, F, G);
+impl_plugins_for_tuple!(A, B, C, D, E, F, G, H);
+
 type Handlers = SmallVec<[Arc<dyn Fn(&mut AppContext) -> bool + Send + Sync + 'static>; 2]>;
+type EventHandlers =
+    SmallVec<[Arc<dyn Fn(&mut AppContext, &dyn Any) -> bool + Send + Sync + 'static>; 2]>;
+
+/// A coarse ordering label for observer and event-subscriber dispatch, loosely
+/// modeled on Bevy's `First`/`Update`/`Last` schedule labels. `flush_effects`
+/// runs each phase's handlers to a fixpoint before advancing to the next, so
+/// e.g. `Phase::Last` observers are guaranteed to see the fully settled result
+/// of every `Phase::Update` reaction to the same batch of effects.
+///
+/// This ordering only takes effect once `flush_effects` actually runs, which
+/// happens via [`AppContext::update_entity`]/[`AppContext::update_global`] —
+/// see their docs for when a given update triggers a flush.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    First,
+    Update,
+    Last,
+}
+
+impl Phase {
+    const ORDERED: [Phase; 3] = [Phase::First, Phase::Update, Phase::Last];
+}
+
+impl Default for Phase {
+    fn default() -> Self {
+        Phase::Update
+    }
+}
 
 pub struct AppContext {
     this: Weak<Mutex<AppContext>>,
+    dispatcher: Arc<dyn PlatformDispatcher>,
     platform: MainThreadOnly<dyn Platform>,
     text_system: Arc<TextSystem>,
     pub(crate) unit_entity_id: EntityId,
@@ -72,7 +177,16 @@ pub struct AppContext {
     pub(crate) windows: SlotMap<WindowId, Option<Window>>,
     pending_updates: usize,
     pub(crate) pending_effects: VecDeque<Effect>,
-    pub(crate) observers: HashMap<EntityId, Handlers>,
+    pub(crate) observers: HashMap<(Phase, EntityId), Handlers>,
+    pub(crate) event_subscribers: HashMap<(Phase, EntityId, TypeId), EventHandlers>,
+    pub(crate) globals: HashMap<TypeId, Box<dyn Any + Send>>,
+    pub(crate) global_observers: HashMap<(Phase, TypeId), Handlers>,
+    pub(crate) plugin_names: HashSet<String>,
+    pub(crate) sub_apps: SubApps,
+    pub(crate) main_thread_entities:
+        MainThreadOnly<SlotMap<MainThreadEntityId, Option<Box<dyn Any>>>>,
+    pub(crate) main_thread_task_tx: ThreadLocalTaskSender,
+    pub(crate) main_thread_task_rx: mpsc::Receiver<ThreadLocalTask>,
     // We recycle this memory across layout requests.
     pub(crate) layout_id_buffer: Vec<LayoutId>,
 }
@@ -93,6 +207,11 @@ impl AppContext {
         let this = self.this.upgrade().unwrap();
         self.platform.read(move |platform| {
             let cx = &mut *this.lock();
+            // `read` guarantees this closure runs on the main thread, so this
+            // is the one place it's sound to drain tasks queued by
+            // `MainThreadHandle::update`, which may have been called from any
+            // thread holding a handle.
+            cx.drain_main_thread_tasks();
             f(platform, cx)
         })
     }
@@ -141,28 +260,202 @@ impl AppContext {
         let result = update(self);
         self.pending_updates -= 1;
         if self.pending_updates == 0 {
+            // Hold `pending_updates` above zero for the duration of the flush
+            // itself, so that an observer/subscriber mutating another entity
+            // mid-flush (now that `update_entity` routes through here too)
+            // can't trigger a nested `flush_effects` call that dispatches the
+            // effects it queues through every phase ahead of the rest of the
+            // batch this call is already working through.
+            self.pending_updates += 1;
             self.flush_effects();
+            self.pending_updates -= 1;
         }
         result
     }
 
+    /// Drain `pending_effects` phase-by-phase: every effect queued so far is
+    /// first dispatched to `Phase::First` observers, run to a fixpoint
+    /// (including any effects those observers themselves queue), then the
+    /// same batch is dispatched to `Phase::Update` observers, then
+    /// `Phase::Last`. This gives deterministic cross-entity ordering, e.g.
+    /// layout-invalidation observers registered in `Phase::Last` always see
+    /// the settled result of `Phase::Update` data-mutation observers.
     fn flush_effects(&mut self) {
-        while let Some(effect) = self.pending_effects.pop_front() {
-            match effect {
-                Effect::Notify(entity_id) => self.apply_notify_effect(entity_id),
+        let mut batch: Vec<Effect> = self.pending_effects.drain(..).collect();
+        for phase in Phase::ORDERED {
+            let mut i = 0;
+            while i < batch.len() {
+                self.apply_effect(phase, &batch[i]);
+                i += 1;
+                batch.extend(self.pending_effects.drain(..));
             }
         }
+        self.update_sub_apps();
     }
 
-    fn apply_notify_effect(&mut self, updated_entity: EntityId) {
-        if let Some(mut handlers) = self.observers.remove(&updated_entity) {
+    fn apply_effect(&mut self, phase: Phase, effect: &Effect) {
+        match effect {
+            Effect::Notify(entity_id) => self.apply_notify_effect(phase, *entity_id),
+            Effect::NotifyGlobalObservers(global_type) => {
+                self.apply_notify_global_effect(phase, *global_type)
+            }
+            Effect::Emit { entity_id, event } => {
+                self.apply_emit_effect(phase, *entity_id, event.as_ref())
+            }
+        }
+    }
+
+    /// Create an entity whose data is `!Send` and must only ever be touched on
+    /// the main thread. Must be called on the main thread, since it evaluates
+    /// `build_entity` inline rather than queuing it as a [`ThreadLocalTask`].
+    pub fn entity_on_main<T: 'static>(
+        &mut self,
+        build_entity: impl FnOnce() -> T,
+    ) -> MainThreadHandle<T> {
+        let entity = Box::new(build_entity()) as Box<dyn Any>;
+        let id = self
+            .main_thread_entities
+            .borrow_on_main_thread_mut()
+            .insert(Some(entity));
+        MainThreadHandle {
+            id,
+            sender: self.main_thread_task_tx.clone(),
+            entity_type: PhantomData,
+        }
+    }
+
+    /// Apply every [`ThreadLocalTask`] enqueued by a [`MainThreadHandle::update`]
+    /// call since the channel was last drained. Borrows the `!Send` entity
+    /// store directly, so this must run on the main thread — it is only ever
+    /// called from [`AppContext::spawn_on_main`], never from the generic
+    /// [`AppContext::flush_effects`] path that background-thread entity
+    /// updates also go through.
+    fn drain_main_thread_tasks(&mut self) {
+        let tasks: Vec<_> = self.main_thread_task_rx.try_iter().collect();
+        if tasks.is_empty() {
+            return;
+        }
+        let entities = self.main_thread_entities.borrow_on_main_thread_mut();
+        for task in tasks {
+            task(entities);
+        }
+    }
+
+    /// Insert a [`SubApp`] under `label`, replacing any sub app already
+    /// installed under that label.
+    pub fn insert_sub_app(&mut self, label: SubAppLabel, sub_app: SubApp) {
+        self.sub_apps.insert(label, sub_app);
+    }
+
+    /// Returns a mutable reference to the sub app installed under `label`, if any.
+    pub fn sub_app_mut(&mut self, label: SubAppLabel) -> Option<&mut SubApp> {
+        self.sub_apps.get_mut(label)
+    }
+
+    /// Returns a reference to the sub app installed under `label`, if any.
+    pub fn sub_app(&self, label: SubAppLabel) -> Option<&SubApp> {
+        self.sub_apps.get(label)
+    }
+
+    /// Run each installed sub app's extract closure against this context and
+    /// then flush the sub app's own pending effects.
+    fn update_sub_apps(&mut self) {
+        let mut sub_apps = std::mem::take(&mut self.sub_apps);
+        sub_apps.update_all(self);
+        self.sub_apps = sub_apps;
+    }
+
+    /// Install a single plugin, returning an error if a plugin with the same
+    /// name has already been installed.
+    pub fn add_plugin(&mut self, plugin: impl Plugin) -> Result<()> {
+        let name = plugin.name().to_string();
+        if !self.plugin_names.insert(name.clone()) {
+            return Err(anyhow!(AppError::DuplicatePlugin { plugin_name: name }));
+        }
+        plugin.build(self);
+        Ok(())
+    }
+
+    fn apply_notify_effect(&mut self, phase: Phase, updated_entity: EntityId) {
+        let key = (phase, updated_entity);
+        if let Some(mut handlers) = self.observers.remove(&key) {
             handlers.retain(|handler| handler(self));
-            if let Some(new_handlers) = self.observers.remove(&updated_entity) {
+            if let Some(new_handlers) = self.observers.remove(&key) {
                 handlers.extend(new_handlers);
             }
-            self.observers.insert(updated_entity, handlers);
+            self.observers.insert(key, handlers);
         }
     }
+
+    fn apply_notify_global_effect(&mut self, phase: Phase, updated_global: TypeId) {
+        let key = (phase, updated_global);
+        if let Some(mut handlers) = self.global_observers.remove(&key) {
+            handlers.retain(|handler| handler(self));
+            if let Some(new_handlers) = self.global_observers.remove(&key) {
+                handlers.extend(new_handlers);
+            }
+            self.global_observers.insert(key, handlers);
+        }
+    }
+
+    fn apply_emit_effect(&mut self, phase: Phase, emitter: EntityId, event: &dyn Any) {
+        let key = (phase, emitter, event.type_id());
+        if let Some(mut handlers) = self.event_subscribers.remove(&key) {
+            handlers.retain(|handler| handler(self, event));
+            if let Some(new_handlers) = self.event_subscribers.remove(&key) {
+                handlers.extend(new_handlers);
+            }
+            self.event_subscribers.insert(key, handlers);
+        }
+    }
+
+    /// Returns the app-wide singleton of type `G`.
+    ///
+    /// Panics if no global of this type has been set via [`AppContext::set_global`].
+    pub fn global<G: Send + 'static>(&self) -> &G {
+        self.globals
+            .get(&TypeId::of::<G>())
+            .and_then(|any| any.downcast_ref())
+            .unwrap_or_else(|| {
+                panic!(
+                    "no global registered of type {}",
+                    std::any::type_name::<G>()
+                )
+            })
+    }
+
+    /// Returns whether a global of type `G` has been set.
+    pub fn has_global<G: Send + 'static>(&self) -> bool {
+        self.globals.contains_key(&TypeId::of::<G>())
+    }
+
+    /// Sets the app-wide singleton of type `G`, overwriting any previous value.
+    pub fn set_global<G: Send + 'static>(&mut self, global: G) {
+        self.globals.insert(TypeId::of::<G>(), Box::new(global));
+    }
+
+    /// Updates the app-wide singleton of type `G`, notifying any observers
+    /// registered via [`ModelContext::observe_global`] once the update completes.
+    ///
+    /// Panics if no global of this type has been set via [`AppContext::set_global`].
+    pub fn update_global<G: Send + 'static, R>(
+        &mut self,
+        update: impl FnOnce(&mut G, &mut Self) -> R,
+    ) -> R {
+        self.update(|cx| {
+            let mut global = cx.globals.remove(&TypeId::of::<G>()).unwrap_or_else(|| {
+                panic!(
+                    "no global registered of type {}",
+                    std::any::type_name::<G>()
+                )
+            });
+            let result = update(global.downcast_mut().unwrap(), cx);
+            cx.globals.insert(TypeId::of::<G>(), global);
+            cx.pending_effects
+                .push_back(Effect::NotifyGlobalObservers(TypeId::of::<G>()));
+            result
+        })
+    }
 }
 
 impl Context for AppContext {
@@ -184,18 +477,20 @@ impl Context for AppContext {
         handle: &Handle<T>,
         update: impl FnOnce(&mut T, &mut Self::EntityContext<'_, '_, T>) -> R,
     ) -> R {
-        let mut entity = self
-            .entities
-            .get_mut(handle.id)
-            .unwrap()
-            .take()
-            .unwrap()
-            .downcast::<T>()
-            .unwrap();
+        self.update(|cx| {
+            let mut entity = cx
+                .entities
+                .get_mut(handle.id)
+                .unwrap()
+                .take()
+                .unwrap()
+                .downcast::<T>()
+                .unwrap();
 
-        let result = update(&mut *entity, &mut ModelContext::mutable(self, handle.id));
-        self.entities.get_mut(handle.id).unwrap().replace(entity);
-        result
+            let result = update(&mut *entity, &mut ModelContext::mutable(cx, handle.id));
+            cx.entities.get_mut(handle.id).unwrap().replace(entity);
+            result
+        })
     }
 }
 
@@ -246,16 +541,29 @@ impl<'a, T: Send + Sync + 'static> ModelContext<'a, T> {
         }
     }
 
+    /// Invoke `on_notify` whenever `handle`'s entity calls [`ModelContext::notify`],
+    /// running in [`Phase::Update`]. See [`ModelContext::observe_in`] to pick a
+    /// different phase.
     pub fn observe<E: Send + Sync + 'static>(
         &mut self,
         handle: &Handle<E>,
         on_notify: impl Fn(&mut T, Handle<E>, &mut ModelContext<'_, T>) + Send + Sync + 'static,
+    ) {
+        self.observe_in(Phase::default(), handle, on_notify)
+    }
+
+    /// Like [`ModelContext::observe`], but runs in the given `phase`.
+    pub fn observe_in<E: Send + Sync + 'static>(
+        &mut self,
+        phase: Phase,
+        handle: &Handle<E>,
+        on_notify: impl Fn(&mut T, Handle<E>, &mut ModelContext<'_, T>) + Send + Sync + 'static,
     ) {
         let this = self.handle();
         let handle = handle.downgrade();
         self.app
             .observers
-            .entry(handle.id)
+            .entry((phase, handle.id))
             .or_default()
             .push(Arc::new(move |cx| {
                 if let Some((this, handle)) = this.upgrade(cx).zip(handle.upgrade(cx)) {
@@ -272,6 +580,87 @@ impl<'a, T: Send + Sync + 'static> ModelContext<'a, T> {
             .pending_effects
             .push_back(Effect::Notify(self.entity_id));
     }
+
+    /// Invoke `on_notify` whenever the app-wide global of type `G` is updated
+    /// via [`AppContext::update_global`], running in [`Phase::Update`]. See
+    /// [`ModelContext::observe_global_in`] to pick a different phase.
+    pub fn observe_global<G: Send + 'static>(
+        &mut self,
+        on_notify: impl Fn(&mut T, &mut ModelContext<'_, T>) + Send + Sync + 'static,
+    ) {
+        self.observe_global_in::<G>(Phase::default(), on_notify)
+    }
+
+    /// Like [`ModelContext::observe_global`], but runs in the given `phase`.
+    pub fn observe_global_in<G: Send + 'static>(
+        &mut self,
+        phase: Phase,
+        on_notify: impl Fn(&mut T, &mut ModelContext<'_, T>) + Send + Sync + 'static,
+    ) {
+        let this = self.handle();
+        self.app
+            .global_observers
+            .entry((phase, TypeId::of::<G>()))
+            .or_default()
+            .push(Arc::new(move |cx| {
+                if let Some(this) = this.upgrade(cx) {
+                    this.update(cx, |this, cx| on_notify(this, cx));
+                    true
+                } else {
+                    false
+                }
+            }));
+    }
+
+    /// Emit an event of type `Evt` from this entity, to be dispatched to any
+    /// handlers registered via [`ModelContext::subscribe`] for this entity.
+    ///
+    /// Delivery happens the next time [`AppContext::flush_effects`] runs,
+    /// which [`AppContext::update_entity`] (and therefore every
+    /// [`Handle::update`]) triggers once the outermost update returns.
+    pub fn emit<Evt: Send + 'static>(&mut self, event: Evt) {
+        self.app.pending_effects.push_back(Effect::Emit {
+            entity_id: self.entity_id,
+            event: Box::new(event),
+        });
+    }
+
+    /// Invoke `on_event` whenever `handle`'s entity emits an event of type `Evt`
+    /// via [`ModelContext::emit`], running in [`Phase::Update`]. See
+    /// [`ModelContext::subscribe_in`] to pick a different phase.
+    pub fn subscribe<Emitter: Send + Sync + 'static, Evt: Send + 'static>(
+        &mut self,
+        handle: &Handle<Emitter>,
+        on_event: impl Fn(&mut T, &Evt, &mut ModelContext<'_, T>) + Send + Sync + 'static,
+    ) {
+        self.subscribe_in(Phase::default(), handle, on_event)
+    }
+
+    /// Like [`ModelContext::subscribe`], but runs in the given `phase`.
+    pub fn subscribe_in<Emitter: Send + Sync + 'static, Evt: Send + 'static>(
+        &mut self,
+        phase: Phase,
+        handle: &Handle<Emitter>,
+        on_event: impl Fn(&mut T, &Evt, &mut ModelContext<'_, T>) + Send + Sync + 'static,
+    ) {
+        let this = self.handle();
+        let emitter = handle.downgrade();
+        self.app
+            .event_subscribers
+            .entry((phase, handle.id, TypeId::of::<Evt>()))
+            .or_default()
+            .push(Arc::new(move |cx, event| {
+                let event = event
+                    .downcast_ref::<Evt>()
+                    .expect("event type did not match subscription");
+                if let Some((this, _emitter)) = this.upgrade(cx).zip(emitter.upgrade(cx)) {
+                    this.update(cx, |this, cx| on_event(this, event, cx));
+                    true
+                } else {
+                    false
+                }
+            }));
+    }
 }
 
 impl<'a, T: 'static> Context for ModelContext<'a, T> {
@@ -294,6 +683,7 @@ impl<'a, T: 'static> Context for ModelContext<'a, T> {
 }
 
 slotmap::new_key_type! { pub struct EntityId; }
+slotmap::new_key_type! { pub struct MainThreadEntityId; }
 
 pub struct Handle<T> {
     pub(crate) id: EntityId,
@@ -372,13 +762,223 @@ impl<T: Send + Sync + 'static> WeakHandle<T> {
     }
 }
 
+/// A handle to an entity created with [`AppContext::entity_on_main`], whose
+/// data is `!Send` and therefore cannot be addressed the way [`Handle`]
+/// addresses ordinary entities. Unlike `Handle::update`, updates are not
+/// applied in place: they are boxed into a [`ThreadLocalTask`] and sent to the
+/// main thread, where [`AppContext`] applies them the next time
+/// [`AppContext::spawn_on_main`] runs its closure there. Flushing effects does
+/// not apply them.
+pub struct MainThreadHandle<T> {
+    id: MainThreadEntityId,
+    sender: ThreadLocalTaskSender,
+    // `fn() -> T` rather than `T` so the handle stays `Send` even when `T`
+    // isn't: no `T` value ever crosses `sender`, only `Send`-bounded boxed
+    // closures that are handed `&mut T` once they run on the main thread, so
+    // there's nothing `!Send` for this marker to actually stand in for.
+    entity_type: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> MainThreadHandle<T> {
+    /// Queue `update` to run against this entity on the main thread. Returns
+    /// a [`ThreadLocalTaskSendError`] if the main thread's task receiver has
+    /// been dropped (e.g. the app has already shut down).
+    pub fn update(
+        &self,
+        update: impl FnOnce(&mut T) + Send + 'static,
+    ) -> Result<(), ThreadLocalTaskSendError> {
+        let id = self.id;
+        self.sender.send(Box::new(move |entities| {
+            if let Some(Some(entity)) = entities.get_mut(id) {
+                update(entity.downcast_mut::<T>().unwrap());
+            }
+        }))
+    }
+}
+
+impl<T> Clone for MainThreadHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id,
+            sender: self.sender.clone(),
+            entity_type: PhantomData,
+        }
+    }
+}
+
 pub(crate) enum Effect {
     Notify(EntityId),
+    NotifyGlobalObservers(TypeId),
+    Emit {
+        entity_id: EntityId,
+        event: Box<dyn Any + Send>,
+    },
+}
+
+/// An error produced while mutating an [`App`] or [`AppContext`].
+#[derive(Debug, Clone)]
+pub enum AppError {
+    /// Returned by [`AppContext::add_plugin`] when a plugin with the same
+    /// [`Plugin::name`] has already been added.
+    DuplicatePlugin { plugin_name: String },
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::DuplicatePlugin { plugin_name } => {
+                write!(f, "plugin {plugin_name:?} was already added")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+/// A boxed update enqueued by [`MainThreadHandle::update`], applied against
+/// the main-thread entity store by [`AppContext::drain_main_thread_tasks`].
+type ThreadLocalTask =
+    Box<dyn FnOnce(&mut SlotMap<MainThreadEntityId, Option<Box<dyn Any>>>) + Send>;
+
+/// Returned by [`MainThreadHandle::update`] when the main thread's
+/// [`ThreadLocalTask`] receiver has already been dropped, e.g. because the
+/// app has shut down.
+#[derive(Debug, Clone, Copy)]
+pub struct ThreadLocalTaskSendError;
+
+impl fmt::Display for ThreadLocalTaskSendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not send task to main thread: the receiver was dropped"
+        )
+    }
+}
+
+impl std::error::Error for ThreadLocalTaskSendError {}
+
+/// The `Send` half of the channel that routes [`MainThreadHandle`] updates to
+/// the main thread. Safe to hold on [`AppContext`] and clone into handles even
+/// though the entities it ultimately updates are not themselves `Send`.
+#[derive(Clone)]
+pub(crate) struct ThreadLocalTaskSender(mpsc::Sender<ThreadLocalTask>);
+
+impl ThreadLocalTaskSender {
+    fn send(&self, task: ThreadLocalTask) -> Result<(), ThreadLocalTaskSendError> {
+        self.0.send(task).map_err(|_| ThreadLocalTaskSendError)
+    }
+}
+
+/// Identifies a [`SubApp`] installed on an [`AppContext`] via [`AppContext::insert_sub_app`].
+pub type SubAppLabel = &'static str;
+
+type ExtractFn = Box<dyn Fn(&mut AppContext, &mut AppContext) + Send>;
+
+/// An isolated world with its own entities, windows, observers, and effect
+/// queue, updated independently from the main `AppContext` and reconciled
+/// into it via a user-supplied extract closure.
+///
+/// Like the top-level [`App`], a sub app's `AppContext` is backed by its own
+/// `Arc<Mutex<AppContext>>`, so [`AppContext::spawn_on_main`] (and therefore
+/// [`AppContext::open_window`]) work on `cx()`/`cx_mut()` the same way they do
+/// on the main context: they always dispatch through the shared platform.
+pub struct SubApp {
+    cx: Arc<Mutex<AppContext>>,
+    extract: Option<ExtractFn>,
+}
+
+impl SubApp {
+    /// Create a new sub app, sharing `main_cx`'s platform and text system but
+    /// with its own entities, windows, observers, and effect queue.
+    pub fn new(main_cx: &AppContext) -> Self {
+        let mut entities = SlotMap::with_key();
+        let unit_entity_id = entities.insert(Some(Box::new(()) as Box<dyn Any + Send>));
+        let (main_thread_task_tx, main_thread_task_rx) = mpsc::channel();
+        let dispatcher = main_cx.dispatcher.clone();
+        let main_thread_entities = MainThreadOnly::new(SlotMap::with_key(), dispatcher.clone());
+        let platform = main_cx.platform.clone();
+        let text_system = main_cx.text_system.clone();
+        let cx = Arc::new_cyclic(|this| {
+            Mutex::new(AppContext {
+                this: this.clone(),
+                dispatcher,
+                platform,
+                text_system,
+                unit_entity_id,
+                entities,
+                windows: SlotMap::with_key(),
+                pending_updates: 0,
+                pending_effects: Default::default(),
+                observers: Default::default(),
+                event_subscribers: Default::default(),
+                globals: Default::default(),
+                global_observers: Default::default(),
+                plugin_names: Default::default(),
+                sub_apps: Default::default(),
+                main_thread_entities,
+                main_thread_task_tx: ThreadLocalTaskSender(main_thread_task_tx),
+                main_thread_task_rx,
+                layout_id_buffer: Default::default(),
+            })
+        });
+        Self { cx, extract: None }
+    }
+
+    /// Set the closure run against `(main_cx, sub_cx)` each time this sub app
+    /// is updated, before its own pending effects are flushed. This is the
+    /// sync step that reconciles the sub app's world into the main one.
+    pub fn set_extract(
+        &mut self,
+        extract: impl Fn(&mut AppContext, &mut AppContext) + Send + 'static,
+    ) {
+        self.extract = Some(Box::new(extract));
+    }
+
+    pub fn cx(&self) -> MutexGuard<'_, AppContext> {
+        self.cx.lock()
+    }
+
+    pub fn cx_mut(&mut self) -> MutexGuard<'_, AppContext> {
+        self.cx.lock()
+    }
+
+    fn update(&mut self, main_cx: &mut AppContext) {
+        let mut cx = self.cx.lock();
+        if let Some(extract) = &self.extract {
+            extract(main_cx, &mut cx);
+        }
+        cx.flush_effects();
+    }
+}
+
+/// The set of [`SubApp`]s installed on an [`AppContext`], keyed by [`SubAppLabel`].
+#[derive(Default)]
+pub(crate) struct SubApps(HashMap<SubAppLabel, SubApp>);
+
+impl SubApps {
+    fn insert(&mut self, label: SubAppLabel, sub_app: SubApp) {
+        self.0.insert(label, sub_app);
+    }
+
+    fn get(&self, label: SubAppLabel) -> Option<&SubApp> {
+        self.0.get(label)
+    }
+
+    fn get_mut(&mut self, label: SubAppLabel) -> Option<&mut SubApp> {
+        self.0.get_mut(label)
+    }
+
+    fn update_all(&mut self, main_cx: &mut AppContext) {
+        for sub_app in self.0.values_mut() {
+            sub_app.update(main_cx);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::AppContext;
+    use super::*;
+    use std::sync::Mutex as StdMutex;
 
     #[test]
     fn test_app_context_send_sync() {
@@ -386,4 +986,127 @@ mod tests {
         fn assert_send<T: Send>() {}
         assert_send::<AppContext>();
     }
+
+    #[test]
+    fn test_add_plugin_twice_returns_duplicate_plugin_error() {
+        struct LoggingPlugin;
+
+        impl Plugin for LoggingPlugin {
+            fn build(&self, _cx: &mut AppContext) {}
+        }
+
+        App::test().run(|cx| {
+            cx.add_plugin(LoggingPlugin).unwrap();
+            let error = cx.add_plugin(LoggingPlugin).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<AppError>(),
+                Some(AppError::DuplicatePlugin { .. })
+            ));
+        });
+    }
+
+    #[test]
+    fn test_subscribe_receives_emitted_event_but_not_other_event_types() {
+        struct Ping;
+        struct Pong;
+        struct Subject {
+            pings: u32,
+        }
+
+        App::test().run(|cx| {
+            let subject = cx.entity(|cx| {
+                let this = cx.handle().upgrade(&*cx).unwrap();
+                cx.subscribe(&this, |this: &mut Subject, _: &Ping, _cx| {
+                    this.pings += 1;
+                });
+                Subject { pings: 0 }
+            });
+
+            subject.update(cx, |_this, cx| cx.emit(Pong));
+            subject.update(cx, |this, _cx| assert_eq!(this.pings, 0));
+
+            subject.update(cx, |_this, cx| cx.emit(Ping));
+            subject.update(cx, |this, _cx| assert_eq!(this.pings, 1));
+        });
+    }
+
+    #[test]
+    fn test_phases_flush_first_then_update_then_last() {
+        struct Subject;
+
+        App::test().run(|cx| {
+            let log = Arc::new(StdMutex::new(Vec::new()));
+            let subject = cx.entity(|cx| {
+                let this = cx.handle().upgrade(&*cx).unwrap();
+                for (phase, label) in [
+                    (Phase::Last, "last"),
+                    (Phase::First, "first"),
+                    (Phase::Update, "update"),
+                ] {
+                    let log = log.clone();
+                    cx.observe_in(phase, &this, move |_this, _handle, _cx| {
+                        log.lock().unwrap().push(label);
+                    });
+                }
+                Subject
+            });
+
+            subject.update(cx, |_this, cx| cx.notify());
+
+            assert_eq!(*log.lock().unwrap(), vec!["first", "update", "last"]);
+        });
+    }
+
+    #[test]
+    fn test_sub_app_extract_reconciles_into_main_cx_on_flush() {
+        App::test().run(|cx| {
+            cx.set_global(0u32);
+
+            let mut sub_app = SubApp::new(cx);
+            sub_app.cx_mut().set_global(42u32);
+            sub_app.set_extract(|main_cx, sub_cx| {
+                let value = *sub_cx.global::<u32>();
+                main_cx.set_global(value);
+            });
+            cx.insert_sub_app("counters", sub_app);
+
+            // Any flush on the main context reconciles every installed sub
+            // app via its extract closure before the main update returns.
+            cx.update_global::<u32, _>(|_, _| {});
+
+            assert_eq!(*cx.global::<u32>(), 42);
+        });
+    }
+
+    #[test]
+    fn test_main_thread_handle_update_is_applied_when_drained() {
+        struct Counter(u32);
+
+        App::test().run(|cx| {
+            let handle = cx.entity_on_main(|| Counter(0));
+
+            // `MainThreadHandle` exists precisely so a background thread can
+            // queue an update against a `!Send` entity.
+            let handle_for_thread = handle.clone();
+            std::thread::spawn(move || {
+                handle_for_thread.update(|counter| counter.0 = 9).unwrap();
+            })
+            .join()
+            .unwrap();
+
+            cx.drain_main_thread_tasks();
+
+            let value = cx
+                .main_thread_entities
+                .borrow_on_main_thread_mut()
+                .get(handle.id)
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .downcast_ref::<Counter>()
+                .unwrap()
+                .0;
+            assert_eq!(value, 9);
+        });
+    }
 }